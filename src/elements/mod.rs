@@ -0,0 +1,13 @@
+//! Elements of the WebAssembly binary format.
+
+mod name_section;
+mod reloc_section;
+
+pub use self::{
+	name_section::{
+		DataNameSubsection, ElementNameSubsection, FunctionNameSubsection, GlobalNameSubsection,
+		LabelNameSubsection, LocalNameSubsection, MemoryNameSubsection, ModuleNameSubsection,
+		NameMap, NameSection, TableNameSubsection, TypeNameSubsection,
+	},
+	reloc_section::{RelocEntry, RelocSection},
+};
@@ -1,5 +1,5 @@
 use crate::io;
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 use super::{
 	index_map::IndexMap, Deserialize, Error, Module, Serialize, Type, VarUint32, VarUint7,
@@ -8,8 +8,16 @@ use super::{
 const NAME_TYPE_MODULE: u8 = 0;
 const NAME_TYPE_FUNCTION: u8 = 1;
 const NAME_TYPE_LOCAL: u8 = 2;
+const NAME_TYPE_LABEL: u8 = 3;
+const NAME_TYPE_TYPE: u8 = 4;
+const NAME_TYPE_TABLE: u8 = 5;
+const NAME_TYPE_MEMORY: u8 = 6;
+const NAME_TYPE_GLOBAL: u8 = 7;
+const NAME_TYPE_ELEM: u8 = 8;
+const NAME_TYPE_DATA: u8 = 9;
 
 /// Debug name information.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct NameSection {
 	/// Module name subsection.
@@ -20,6 +28,31 @@ pub struct NameSection {
 
 	/// Local name subsection.
 	locals: Option<LocalNameSubsection>,
+
+	/// Label name subsection.
+	labels: Option<LabelNameSubsection>,
+
+	/// Type name subsection.
+	types: Option<TypeNameSubsection>,
+
+	/// Table name subsection.
+	tables: Option<TableNameSubsection>,
+
+	/// Memory name subsection.
+	memories: Option<MemoryNameSubsection>,
+
+	/// Global name subsection.
+	globals: Option<GlobalNameSubsection>,
+
+	/// Element segment name subsection.
+	elements: Option<ElementNameSubsection>,
+
+	/// Data segment name subsection.
+	data: Option<DataNameSubsection>,
+
+	/// Subsections whose `name_type` is not recognised, preserved verbatim as
+	/// `(name_type, raw_payload)` so that round-tripping stays byte-faithful.
+	unparsed: Vec<(u8, Vec<u8>)>,
 }
 
 impl NameSection {
@@ -29,7 +62,19 @@ impl NameSection {
 		functions: Option<FunctionNameSubsection>,
 		locals: Option<LocalNameSubsection>,
 	) -> Self {
-		Self { module, functions, locals }
+		Self {
+			module,
+			functions,
+			locals,
+			labels: None,
+			types: None,
+			tables: None,
+			memories: None,
+			globals: None,
+			elements: None,
+			data: None,
+			unparsed: Vec::new(),
+		}
 	}
 
 	/// Module name subsection of this section.
@@ -61,6 +106,87 @@ impl NameSection {
 	pub fn locals_mut(&mut self) -> &mut Option<LocalNameSubsection> {
 		&mut self.locals
 	}
+
+	/// Label name subsection of this section.
+	pub fn labels(&self) -> Option<&LabelNameSubsection> {
+		self.labels.as_ref()
+	}
+
+	/// Label name subsection of this section (mutable).
+	pub fn labels_mut(&mut self) -> &mut Option<LabelNameSubsection> {
+		&mut self.labels
+	}
+
+	/// Type name subsection of this section.
+	pub fn types(&self) -> Option<&TypeNameSubsection> {
+		self.types.as_ref()
+	}
+
+	/// Type name subsection of this section (mutable).
+	pub fn types_mut(&mut self) -> &mut Option<TypeNameSubsection> {
+		&mut self.types
+	}
+
+	/// Table name subsection of this section.
+	pub fn tables(&self) -> Option<&TableNameSubsection> {
+		self.tables.as_ref()
+	}
+
+	/// Table name subsection of this section (mutable).
+	pub fn tables_mut(&mut self) -> &mut Option<TableNameSubsection> {
+		&mut self.tables
+	}
+
+	/// Memory name subsection of this section.
+	pub fn memories(&self) -> Option<&MemoryNameSubsection> {
+		self.memories.as_ref()
+	}
+
+	/// Memory name subsection of this section (mutable).
+	pub fn memories_mut(&mut self) -> &mut Option<MemoryNameSubsection> {
+		&mut self.memories
+	}
+
+	/// Global name subsection of this section.
+	pub fn globals(&self) -> Option<&GlobalNameSubsection> {
+		self.globals.as_ref()
+	}
+
+	/// Global name subsection of this section (mutable).
+	pub fn globals_mut(&mut self) -> &mut Option<GlobalNameSubsection> {
+		&mut self.globals
+	}
+
+	/// Element segment name subsection of this section.
+	pub fn elements(&self) -> Option<&ElementNameSubsection> {
+		self.elements.as_ref()
+	}
+
+	/// Element segment name subsection of this section (mutable).
+	pub fn elements_mut(&mut self) -> &mut Option<ElementNameSubsection> {
+		&mut self.elements
+	}
+
+	/// Data segment name subsection of this section.
+	pub fn data(&self) -> Option<&DataNameSubsection> {
+		self.data.as_ref()
+	}
+
+	/// Data segment name subsection of this section (mutable).
+	pub fn data_mut(&mut self) -> &mut Option<DataNameSubsection> {
+		&mut self.data
+	}
+
+	/// Unrecognised subsections of this section, stored as `(name_type,
+	/// raw_payload)` pairs.
+	pub fn unparsed(&self) -> &[(u8, Vec<u8>)] {
+		&self.unparsed
+	}
+
+	/// Unrecognised subsections of this section (mutable).
+	pub fn unparsed_mut(&mut self) -> &mut Vec<(u8, Vec<u8>)> {
+		&mut self.unparsed
+	}
 }
 
 impl NameSection {
@@ -72,6 +198,14 @@ impl NameSection {
 		let mut module_name: Option<ModuleNameSubsection> = None;
 		let mut function_names: Option<FunctionNameSubsection> = None;
 		let mut local_names: Option<LocalNameSubsection> = None;
+		let mut label_names: Option<LabelNameSubsection> = None;
+		let mut type_names: Option<TypeNameSubsection> = None;
+		let mut table_names: Option<TableNameSubsection> = None;
+		let mut memory_names: Option<MemoryNameSubsection> = None;
+		let mut global_names: Option<GlobalNameSubsection> = None;
+		let mut elem_names: Option<ElementNameSubsection> = None;
+		let mut data_names: Option<DataNameSubsection> = None;
+		let mut unparsed: Vec<(u8, Vec<u8>)> = Vec::new();
 
 		while let Ok(raw_subsection_type) = VarUint7::deserialize(rdr) {
 			let subsection_type = raw_subsection_type.into();
@@ -100,18 +234,84 @@ impl NameSection {
 					local_names = Some(LocalNameSubsection::deserialize(module, rdr)?);
 				},
 
+				NAME_TYPE_LABEL => {
+					if label_names.is_some() {
+						return Err(Error::DuplicatedNameSubsections(NAME_TYPE_LABEL));
+					}
+					label_names = Some(LabelNameSubsection::deserialize(module, rdr)?);
+				},
+
+				NAME_TYPE_TYPE => {
+					if type_names.is_some() {
+						return Err(Error::DuplicatedNameSubsections(NAME_TYPE_TYPE));
+					}
+					type_names = Some(TypeNameSubsection::deserialize(module, rdr)?);
+				},
+
+				NAME_TYPE_TABLE => {
+					if table_names.is_some() {
+						return Err(Error::DuplicatedNameSubsections(NAME_TYPE_TABLE));
+					}
+					table_names = Some(TableNameSubsection::deserialize(module, rdr)?);
+				},
+
+				NAME_TYPE_MEMORY => {
+					if memory_names.is_some() {
+						return Err(Error::DuplicatedNameSubsections(NAME_TYPE_MEMORY));
+					}
+					memory_names = Some(MemoryNameSubsection::deserialize(module, rdr)?);
+				},
+
+				NAME_TYPE_GLOBAL => {
+					if global_names.is_some() {
+						return Err(Error::DuplicatedNameSubsections(NAME_TYPE_GLOBAL));
+					}
+					global_names = Some(GlobalNameSubsection::deserialize(module, rdr)?);
+				},
+
+				NAME_TYPE_ELEM => {
+					if elem_names.is_some() {
+						return Err(Error::DuplicatedNameSubsections(NAME_TYPE_ELEM));
+					}
+					elem_names = Some(ElementNameSubsection::deserialize(module, rdr)?);
+				},
+
+				NAME_TYPE_DATA => {
+					if data_names.is_some() {
+						return Err(Error::DuplicatedNameSubsections(NAME_TYPE_DATA));
+					}
+					data_names = Some(DataNameSubsection::deserialize(module, rdr)?);
+				},
+
 				_ => {
-					let offset: i64 = size.try_into().map_err(|_| Error::UnexpectedEof)?;
-					rdr.seek_relative(offset)?;
-					// Behavior of seeking past the length is implementation defined, so we need a way to force an EOF error.
-					if rdr.stream_position()? > rdr.stream_len()? {
+					// Read the payload verbatim rather than seeking past it, so that
+					// re-serializing the section reproduces the original bytes. Bound
+					// the claimed size against the remaining stream first, so a bogus
+					// length cannot trigger a huge allocation.
+					let remaining = rdr.stream_len()?.saturating_sub(rdr.stream_position()?);
+					if size as u64 > remaining {
 						return Err(Error::UnexpectedEof);
 					}
+					let mut name_payload = alloc::vec![0u8; size];
+					rdr.read(&mut name_payload)?;
+					unparsed.push((subsection_type, name_payload));
 				},
 			};
 		}
 
-		Ok(Self { module: module_name, functions: function_names, locals: local_names })
+		Ok(Self {
+			module: module_name,
+			functions: function_names,
+			locals: local_names,
+			labels: label_names,
+			types: type_names,
+			tables: table_names,
+			memories: memory_names,
+			globals: global_names,
+			elements: elem_names,
+			data: data_names,
+			unparsed,
+		})
 	}
 }
 
@@ -124,11 +324,15 @@ impl Serialize for NameSection {
 			name_type: u8,
 			name_payload: &[u8],
 		) -> Result<(), Error> {
-			VarUint7::from(name_type).serialize(wtr)?;
+			let mut header = vec![];
+			VarUint7::from(name_type).serialize(&mut header)?;
 			VarUint32::try_from(name_payload.len())
 				.map_err(|_| Error::InvalidVarInt32)?
-				.serialize(wtr)?;
-			wtr.write(name_payload).map_err(Into::into)
+				.serialize(&mut header)?;
+			// Flush the subsection header and its payload in a single vectored
+			// write rather than issuing a separate write for each.
+			wtr.write_vectored(&[io::IoSlice::new(&header), io::IoSlice::new(name_payload)])
+				.map_err(Into::into)
 		}
 
 		if let Some(module_name_subsection) = self.module {
@@ -149,11 +353,60 @@ impl Serialize for NameSection {
 			serialize_subsection(wtr, NAME_TYPE_LOCAL, &buffer)?;
 		}
 
+		if let Some(label_name_subsection) = self.labels {
+			let mut buffer = vec![];
+			label_name_subsection.serialize(&mut buffer)?;
+			serialize_subsection(wtr, NAME_TYPE_LABEL, &buffer)?;
+		}
+
+		if let Some(type_name_subsection) = self.types {
+			let mut buffer = vec![];
+			type_name_subsection.serialize(&mut buffer)?;
+			serialize_subsection(wtr, NAME_TYPE_TYPE, &buffer)?;
+		}
+
+		if let Some(table_name_subsection) = self.tables {
+			let mut buffer = vec![];
+			table_name_subsection.serialize(&mut buffer)?;
+			serialize_subsection(wtr, NAME_TYPE_TABLE, &buffer)?;
+		}
+
+		if let Some(memory_name_subsection) = self.memories {
+			let mut buffer = vec![];
+			memory_name_subsection.serialize(&mut buffer)?;
+			serialize_subsection(wtr, NAME_TYPE_MEMORY, &buffer)?;
+		}
+
+		if let Some(global_name_subsection) = self.globals {
+			let mut buffer = vec![];
+			global_name_subsection.serialize(&mut buffer)?;
+			serialize_subsection(wtr, NAME_TYPE_GLOBAL, &buffer)?;
+		}
+
+		if let Some(elem_name_subsection) = self.elements {
+			let mut buffer = vec![];
+			elem_name_subsection.serialize(&mut buffer)?;
+			serialize_subsection(wtr, NAME_TYPE_ELEM, &buffer)?;
+		}
+
+		if let Some(data_name_subsection) = self.data {
+			let mut buffer = vec![];
+			data_name_subsection.serialize(&mut buffer)?;
+			serialize_subsection(wtr, NAME_TYPE_DATA, &buffer)?;
+		}
+
+		let mut unparsed = self.unparsed;
+		unparsed.sort_by_key(|(name_type, _)| *name_type);
+		for (name_type, name_payload) in &unparsed {
+			serialize_subsection(wtr, *name_type, name_payload)?;
+		}
+
 		Ok(())
 	}
 }
 
 /// The name of this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ModuleNameSubsection {
 	name: String,
@@ -194,8 +447,10 @@ impl Deserialize for ModuleNameSubsection {
 }
 
 /// The names of the functions in this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct FunctionNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_name_map"))]
 	names: NameMap,
 }
 
@@ -229,8 +484,10 @@ impl Serialize for FunctionNameSubsection {
 }
 
 /// The names of the local variables in this module's functions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct LocalNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_nested_name_map"))]
 	local_names: IndexMap<NameMap>,
 }
 
@@ -296,9 +553,338 @@ impl Serialize for LocalNameSubsection {
 	}
 }
 
+/// The names of the types in this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TypeNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_name_map"))]
+	names: NameMap,
+}
+
+impl TypeNameSubsection {
+	/// A map from type indices to names.
+	pub fn names(&self) -> &NameMap {
+		&self.names
+	}
+
+	/// A map from type indices to names (mutable).
+	pub fn names_mut(&mut self) -> &mut NameMap {
+		&mut self.names
+	}
+
+	/// Deserialize names, making sure that all names correspond to types.
+	pub fn deserialize<R: io::Read>(
+		module: &Module,
+		rdr: &mut R,
+	) -> Result<TypeNameSubsection, Error> {
+		let max = module.type_section().map(|ts| ts.types().len()).unwrap_or(0);
+		let names = IndexMap::deserialize(max, rdr)?;
+		Ok(TypeNameSubsection { names })
+	}
+}
+
+impl Serialize for TypeNameSubsection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		self.names.serialize(wtr)
+	}
+}
+
+/// The names of the tables in this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_name_map"))]
+	names: NameMap,
+}
+
+impl TableNameSubsection {
+	/// A map from table indices to names.
+	pub fn names(&self) -> &NameMap {
+		&self.names
+	}
+
+	/// A map from table indices to names (mutable).
+	pub fn names_mut(&mut self) -> &mut NameMap {
+		&mut self.names
+	}
+
+	/// Deserialize names, making sure that all names correspond to tables.
+	pub fn deserialize<R: io::Read>(
+		module: &Module,
+		rdr: &mut R,
+	) -> Result<TableNameSubsection, Error> {
+		let names = IndexMap::deserialize(module.table_space(), rdr)?;
+		Ok(TableNameSubsection { names })
+	}
+}
+
+impl Serialize for TableNameSubsection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		self.names.serialize(wtr)
+	}
+}
+
+/// The names of the memories in this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_name_map"))]
+	names: NameMap,
+}
+
+impl MemoryNameSubsection {
+	/// A map from memory indices to names.
+	pub fn names(&self) -> &NameMap {
+		&self.names
+	}
+
+	/// A map from memory indices to names (mutable).
+	pub fn names_mut(&mut self) -> &mut NameMap {
+		&mut self.names
+	}
+
+	/// Deserialize names, making sure that all names correspond to memories.
+	pub fn deserialize<R: io::Read>(
+		module: &Module,
+		rdr: &mut R,
+	) -> Result<MemoryNameSubsection, Error> {
+		let names = IndexMap::deserialize(module.memory_space(), rdr)?;
+		Ok(MemoryNameSubsection { names })
+	}
+}
+
+impl Serialize for MemoryNameSubsection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		self.names.serialize(wtr)
+	}
+}
+
+/// The names of the globals in this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GlobalNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_name_map"))]
+	names: NameMap,
+}
+
+impl GlobalNameSubsection {
+	/// A map from global indices to names.
+	pub fn names(&self) -> &NameMap {
+		&self.names
+	}
+
+	/// A map from global indices to names (mutable).
+	pub fn names_mut(&mut self) -> &mut NameMap {
+		&mut self.names
+	}
+
+	/// Deserialize names, making sure that all names correspond to globals.
+	pub fn deserialize<R: io::Read>(
+		module: &Module,
+		rdr: &mut R,
+	) -> Result<GlobalNameSubsection, Error> {
+		let names = IndexMap::deserialize(module.globals_space(), rdr)?;
+		Ok(GlobalNameSubsection { names })
+	}
+}
+
+impl Serialize for GlobalNameSubsection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		self.names.serialize(wtr)
+	}
+}
+
+/// The names of the element segments in this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ElementNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_name_map"))]
+	names: NameMap,
+}
+
+impl ElementNameSubsection {
+	/// A map from element segment indices to names.
+	pub fn names(&self) -> &NameMap {
+		&self.names
+	}
+
+	/// A map from element segment indices to names (mutable).
+	pub fn names_mut(&mut self) -> &mut NameMap {
+		&mut self.names
+	}
+
+	/// Deserialize names, making sure that all names correspond to element
+	/// segments.
+	pub fn deserialize<R: io::Read>(
+		module: &Module,
+		rdr: &mut R,
+	) -> Result<ElementNameSubsection, Error> {
+		let max = module.elements_section().map(|s| s.entries().len()).unwrap_or(0);
+		let names = IndexMap::deserialize(max, rdr)?;
+		Ok(ElementNameSubsection { names })
+	}
+}
+
+impl Serialize for ElementNameSubsection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		self.names.serialize(wtr)
+	}
+}
+
+/// The names of the data segments in this module.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_name_map"))]
+	names: NameMap,
+}
+
+impl DataNameSubsection {
+	/// A map from data segment indices to names.
+	pub fn names(&self) -> &NameMap {
+		&self.names
+	}
+
+	/// A map from data segment indices to names (mutable).
+	pub fn names_mut(&mut self) -> &mut NameMap {
+		&mut self.names
+	}
+
+	/// Deserialize names, making sure that all names correspond to data
+	/// segments.
+	pub fn deserialize<R: io::Read>(
+		module: &Module,
+		rdr: &mut R,
+	) -> Result<DataNameSubsection, Error> {
+		let max = module.data_section().map(|s| s.entries().len()).unwrap_or(0);
+		let names = IndexMap::deserialize(max, rdr)?;
+		Ok(DataNameSubsection { names })
+	}
+}
+
+impl Serialize for DataNameSubsection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		self.names.serialize(wtr)
+	}
+}
+
+/// The names of the labels in this module's functions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LabelNameSubsection {
+	#[cfg_attr(feature = "serde", serde(with = "serde_nested_name_map"))]
+	label_names: IndexMap<NameMap>,
+}
+
+impl LabelNameSubsection {
+	/// A map from function indices to a map from label indices to names.
+	pub fn label_names(&self) -> &IndexMap<NameMap> {
+		&self.label_names
+	}
+
+	/// A map from function indices to a map from label indices to names
+	/// (mutable).
+	pub fn label_names_mut(&mut self) -> &mut IndexMap<NameMap> {
+		&mut self.label_names
+	}
+
+	/// Deserialize names, making sure that the outer map corresponds to
+	/// functions.
+	pub fn deserialize<R: io::Read>(
+		module: &Module,
+		rdr: &mut R,
+	) -> Result<LabelNameSubsection, Error> {
+		let max_entry_space = module.functions_space();
+
+		// Labels are assigned per instruction, so there is no cheap upper bound
+		// to validate the inner index against.
+		let deserialize_labels =
+			|_: u32, rdr: &mut R| IndexMap::deserialize(u32::MAX as usize, rdr);
+
+		let label_names = IndexMap::deserialize_with(max_entry_space, &deserialize_labels, rdr)?;
+		Ok(LabelNameSubsection { label_names })
+	}
+}
+
+impl Serialize for LabelNameSubsection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		self.label_names.serialize(wtr)
+	}
+}
+
 /// A map from indices to names.
 pub type NameMap = IndexMap<String>;
 
+// `IndexMap` lives outside this crate's serde surface, so the subsection types
+// that wrap it use these `serde(with = ...)` shims to round-trip through a
+// `Vec` of `(index, name)` pairs instead of requiring serde impls on
+// `IndexMap` itself.
+#[cfg(feature = "serde")]
+mod serde_name_map {
+	use super::{IndexMap, NameMap};
+	use alloc::{string::String, vec::Vec};
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	pub fn serialize<S: Serializer>(map: &NameMap, serializer: S) -> Result<S::Ok, S::Error> {
+		map.iter().collect::<Vec<(u32, &String)>>().serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NameMap, D::Error> {
+		let entries = Vec::<(u32, String)>::deserialize(deserializer)?;
+		let mut map = IndexMap::default();
+		for (index, name) in entries {
+			map.insert(index, name);
+		}
+		Ok(map)
+	}
+}
+
+#[cfg(feature = "serde")]
+mod serde_nested_name_map {
+	use super::{IndexMap, NameMap};
+	use alloc::{string::String, vec::Vec};
+	use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+	pub fn serialize<S: Serializer>(
+		map: &IndexMap<NameMap>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		map.iter()
+			.map(|(index, inner)| (index, inner.iter().collect::<Vec<(u32, &String)>>()))
+			.collect::<Vec<(u32, Vec<(u32, &String)>)>>()
+			.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<IndexMap<NameMap>, D::Error> {
+		let entries = Vec::<(u32, Vec<(u32, String)>)>::deserialize(deserializer)?;
+		let mut map = IndexMap::default();
+		for (index, inner_entries) in entries {
+			let mut inner = NameMap::default();
+			for (inner_index, name) in inner_entries {
+				inner.insert(inner_index, name);
+			}
+			map.insert(index, inner);
+		}
+		Ok(map)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{
@@ -308,13 +894,38 @@ mod tests {
 
 	use super::*;
 
-	// A helper function for the tests. Serialize a section, deserialize it,
-	// and make sure it matches the original.
+	// A module large enough that every index used by the test subsections is
+	// in bounds during deserialization: two functions (each with two params,
+	// so the local/label index spaces are non-empty) and two types.
+	fn test_module() -> Module {
+		use crate::builder;
+
+		builder::module()
+			.function()
+			.signature()
+			.with_params(alloc::vec![elements::ValueType::I32, elements::ValueType::I32])
+			.build()
+			.build()
+			.function()
+			.signature()
+			.with_params(alloc::vec![elements::ValueType::I32, elements::ValueType::I32])
+			.build()
+			.build()
+			.build()
+	}
+
+	// A helper function for the tests. Serialize a section, deserialize it back
+	// against a representative module, and make sure it matches the original.
 	fn serialize_test(original: NameSection) -> Vec<u8> {
 		let mut buffer = vec![];
-		original.serialize(&mut buffer).expect("serialize error");
+		original.clone().serialize(&mut buffer).expect("serialize error");
+
+		let module = test_module();
+		let mut cur = crate::io::Cursor::new(buffer.as_slice());
+		let parsed = NameSection::deserialize(&module, &mut cur).expect("deserialize error");
+		assert_eq!(original, parsed);
+
 		buffer
-		// todo: add deserialization to this test
 	}
 
 	#[test]
@@ -365,6 +976,34 @@ mod tests {
 		serialize_test(name_section);
 	}
 
+	#[test]
+	fn serialize_extended_subsections() {
+		let mut type_name_subsection = TypeNameSubsection::default();
+		type_name_subsection.names_mut().insert(0, "my_type".to_string());
+
+		let mut label_name_subsection = LabelNameSubsection::default();
+		let mut labels = NameMap::default();
+		labels.insert(0, "entry".to_string());
+		label_name_subsection.label_names_mut().insert(0, labels);
+
+		let mut name_section = NameSection::new(None, None, None);
+		*name_section.types_mut() = Some(type_name_subsection);
+		*name_section.labels_mut() = Some(label_name_subsection);
+		serialize_test(name_section);
+	}
+
+	#[test]
+	fn serialize_unparsed_subsections() {
+		// Use name_types beyond the recognized range (0..=9) so they survive a
+		// round-trip as opaque blobs instead of being reparsed as typed
+		// subsections. They are inserted in ascending order to match the order
+		// `serialize` emits (and therefore `deserialize` reads) them back.
+		let mut name_section = NameSection::new(None, None, None);
+		name_section.unparsed_mut().push((10, alloc::vec![1, 2, 3]));
+		name_section.unparsed_mut().push((11, alloc::vec![4, 5]));
+		serialize_test(name_section);
+	}
+
 	#[test]
 	fn deserialize_invalid_name_section() {
 		let invalid = {
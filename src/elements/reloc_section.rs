@@ -0,0 +1,216 @@
+use crate::io;
+use alloc::vec::Vec;
+
+use super::{Deserialize, Error, Serialize, VarInt32, VarUint32};
+
+// Relocation kinds as emitted by LLVM/lld for relocatable wasm objects.
+const R_WASM_FUNCTION_INDEX_LEB: u8 = 0;
+const R_WASM_TABLE_INDEX_SLEB: u8 = 1;
+const R_WASM_TABLE_INDEX_I32: u8 = 2;
+const R_WASM_MEMORY_ADDR_LEB: u8 = 3;
+const R_WASM_MEMORY_ADDR_SLEB: u8 = 4;
+const R_WASM_MEMORY_ADDR_I32: u8 = 5;
+const R_WASM_TYPE_INDEX_LEB: u8 = 6;
+const R_WASM_GLOBAL_INDEX_LEB: u8 = 7;
+const R_WASM_FUNCTION_OFFSET_I32: u8 = 8;
+const R_WASM_SECTION_OFFSET_I32: u8 = 9;
+
+/// Whether the given relocation kind carries an addend.
+fn has_addend(reloc_type: u8) -> bool {
+	matches!(
+		reloc_type,
+		R_WASM_MEMORY_ADDR_LEB |
+			R_WASM_MEMORY_ADDR_SLEB |
+			R_WASM_MEMORY_ADDR_I32 |
+			R_WASM_FUNCTION_OFFSET_I32 |
+			R_WASM_SECTION_OFFSET_I32
+	)
+}
+
+/// A `reloc.<section>` custom section describing relocations that a linker
+/// must apply to another section of the same object file.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelocSection {
+	/// Index of the section these relocations apply to.
+	section: u32,
+
+	/// The relocation entries.
+	entries: Vec<RelocEntry>,
+}
+
+impl RelocSection {
+	/// Creates a new relocation section targeting `section`.
+	pub fn new(section: u32, entries: Vec<RelocEntry>) -> Self {
+		RelocSection { section, entries }
+	}
+
+	/// Index of the section these relocations apply to.
+	pub fn section(&self) -> u32 {
+		self.section
+	}
+
+	/// Index of the section these relocations apply to (mutable).
+	pub fn section_mut(&mut self) -> &mut u32 {
+		&mut self.section
+	}
+
+	/// The relocation entries.
+	pub fn entries(&self) -> &[RelocEntry] {
+		&self.entries
+	}
+
+	/// The relocation entries (mutable).
+	pub fn entries_mut(&mut self) -> &mut Vec<RelocEntry> {
+		&mut self.entries
+	}
+}
+
+impl Deserialize for RelocSection {
+	type Error = Error;
+
+	fn deserialize<R: io::Read>(rdr: &mut R) -> Result<RelocSection, Error> {
+		let section = u32::from(VarUint32::deserialize(rdr)?);
+		let count: usize = VarUint32::deserialize(rdr)?.into();
+
+		// Don't pre-allocate from the untrusted `count`: a malformed section
+		// could declare a huge value and turn the reservation into an abort.
+		// Push into a plain `Vec` and let a truncated stream EOF out per entry.
+		let mut entries = Vec::new();
+		for _ in 0..count {
+			entries.push(RelocEntry::deserialize(rdr)?);
+		}
+
+		Ok(RelocSection { section, entries })
+	}
+}
+
+impl Serialize for RelocSection {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		VarUint32::from(self.section).serialize(wtr)?;
+		VarUint32::try_from(self.entries.len())
+			.map_err(|_| Error::InvalidVarInt32)?
+			.serialize(wtr)?;
+		for entry in self.entries {
+			entry.serialize(wtr)?;
+		}
+		Ok(())
+	}
+}
+
+/// A single relocation entry.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelocEntry {
+	/// The relocation kind.
+	reloc_type: u8,
+
+	/// Offset of the value to rewrite, relative to the start of the target
+	/// section's contents.
+	offset: u32,
+
+	/// Index into the relevant index space (symbol, type, etc.).
+	index: u32,
+
+	/// Addend to add to the relocated value. Only present for the memory-
+	/// address and offset relocation kinds.
+	addend: Option<i32>,
+}
+
+impl RelocEntry {
+	/// Creates a new relocation entry.
+	pub fn new(reloc_type: u8, offset: u32, index: u32, addend: Option<i32>) -> Self {
+		RelocEntry { reloc_type, offset, index, addend }
+	}
+
+	/// The relocation kind.
+	pub fn reloc_type(&self) -> u8 {
+		self.reloc_type
+	}
+
+	/// Offset of the value to rewrite.
+	pub fn offset(&self) -> u32 {
+		self.offset
+	}
+
+	/// Index into the relevant index space.
+	pub fn index(&self) -> u32 {
+		self.index
+	}
+
+	/// Addend to add to the relocated value, if any.
+	pub fn addend(&self) -> Option<i32> {
+		self.addend
+	}
+}
+
+impl Deserialize for RelocEntry {
+	type Error = Error;
+
+	fn deserialize<R: io::Read>(rdr: &mut R) -> Result<RelocEntry, Error> {
+		let reloc_type = u8::from(super::VarUint7::deserialize(rdr)?);
+		let offset = u32::from(VarUint32::deserialize(rdr)?);
+		let index = u32::from(VarUint32::deserialize(rdr)?);
+		let addend =
+			if has_addend(reloc_type) { Some(VarInt32::deserialize(rdr)?.into()) } else { None };
+
+		Ok(RelocEntry { reloc_type, offset, index, addend })
+	}
+}
+
+impl Serialize for RelocEntry {
+	type Error = Error;
+
+	fn serialize<W: io::Write>(self, wtr: &mut W) -> Result<(), Error> {
+		super::VarUint7::from(self.reloc_type).serialize(wtr)?;
+		VarUint32::from(self.offset).serialize(wtr)?;
+		VarUint32::from(self.index).serialize(wtr)?;
+		if let Some(addend) = self.addend {
+			VarInt32::from(addend).serialize(wtr)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Serialize an entry, read it back, and make sure it matches the original.
+	fn roundtrip(entry: RelocEntry) {
+		let mut buffer = vec![];
+		entry.clone().serialize(&mut buffer).expect("serialize error");
+		let mut cur = crate::io::Cursor::new(buffer.as_slice());
+		let parsed = RelocEntry::deserialize(&mut cur).expect("deserialize error");
+		assert_eq!(entry, parsed);
+	}
+
+	#[test]
+	fn entry_without_addend() {
+		roundtrip(RelocEntry::new(R_WASM_FUNCTION_INDEX_LEB, 4, 1, None));
+	}
+
+	#[test]
+	fn entry_with_addend() {
+		roundtrip(RelocEntry::new(R_WASM_MEMORY_ADDR_SLEB, 8, 2, Some(-16)));
+	}
+
+	#[test]
+	fn section_roundtrip() {
+		let section = RelocSection::new(
+			3,
+			vec![
+				RelocEntry::new(R_WASM_TYPE_INDEX_LEB, 0, 0, None),
+				RelocEntry::new(R_WASM_MEMORY_ADDR_I32, 12, 5, Some(32)),
+			],
+		);
+
+		let mut buffer = vec![];
+		section.clone().serialize(&mut buffer).expect("serialize error");
+		let mut cur = crate::io::Cursor::new(buffer.as_slice());
+		let parsed = RelocSection::deserialize(&mut cur).expect("deserialize error");
+		assert_eq!(section, parsed);
+	}
+}
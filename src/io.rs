@@ -28,11 +28,69 @@ pub enum Error {
 /// IO specific Result.
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// A buffer to be written, for use with [`Write::write_vectored`].
+///
+/// A `no_std` friendly counterpart to `std::io::IoSlice`.
+pub struct IoSlice<'a>(&'a [u8]);
+
+impl<'a> IoSlice<'a> {
+	/// Wraps a byte slice for vectored writing.
+	pub fn new(buf: &'a [u8]) -> IoSlice<'a> {
+		IoSlice(buf)
+	}
+}
+
+impl core::ops::Deref for IoSlice<'_> {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		self.0
+	}
+}
+
+/// A buffer to be filled, for use with [`Read::read_vectored`].
+///
+/// A `no_std` friendly counterpart to `std::io::IoSliceMut`.
+pub struct IoSliceMut<'a>(&'a mut [u8]);
+
+impl<'a> IoSliceMut<'a> {
+	/// Wraps a mutable byte slice for vectored reading.
+	pub fn new(buf: &'a mut [u8]) -> IoSliceMut<'a> {
+		IoSliceMut(buf)
+	}
+}
+
+impl core::ops::Deref for IoSliceMut<'_> {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		self.0
+	}
+}
+
+impl core::ops::DerefMut for IoSliceMut<'_> {
+	fn deref_mut(&mut self) -> &mut [u8] {
+		self.0
+	}
+}
+
 pub trait Write {
 	/// Write a buffer of data into this write.
 	///
 	/// All data is written at once.
 	fn write(&mut self, buf: &[u8]) -> Result<()>;
+
+	/// Write a sequence of buffers into this write, in order.
+	///
+	/// All data is written. The default implementation writes each buffer in
+	/// turn through [`Write::write`]; implementations backed by `std::io` can
+	/// gather the buffers into a single syscall.
+	fn write_vectored(&mut self, bufs: &[IoSlice]) -> Result<()> {
+		for buf in bufs {
+			self.write(buf)?;
+		}
+		Ok(())
+	}
 }
 
 pub trait Read {
@@ -40,6 +98,18 @@ pub trait Read {
 	///
 	/// If there is not enough data in this read then `UnexpectedEof` will be returned.
 	fn read(&mut self, buf: &mut [u8]) -> Result<()>;
+
+	/// Read into a sequence of buffers, filling each in order.
+	///
+	/// Every buffer is filled completely. The default implementation reads each
+	/// buffer in turn through [`Read::read`]; implementations backed by
+	/// `std::io` can gather the buffers into a single syscall.
+	fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> Result<()> {
+		for buf in bufs {
+			self.read(buf)?;
+		}
+		Ok(())
+	}
 }
 
 pub trait Seek {
@@ -79,6 +149,22 @@ impl<T: AsRef<[u8]>> Read for Cursor<T> {
 		self.pos += requested;
 		Ok(())
 	}
+
+	fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> Result<()> {
+		let slice = self.inner.as_ref();
+		// Bounds-check the whole request up front so a partially filled set of
+		// buffers is never observed on error.
+		let requested: usize = bufs.iter().map(|b| b.len()).sum();
+		if requested > slice.len() - self.pos {
+			return Err(Error::UnexpectedEof);
+		}
+		for buf in bufs {
+			let len = buf.len();
+			buf.copy_from_slice(&self.inner.as_ref()[self.pos..(self.pos + len)]);
+			self.pos += len;
+		}
+		Ok(())
+	}
 }
 
 impl<T: AsRef<[u8]>> Seek for Cursor<T> {
@@ -110,6 +196,47 @@ impl<T: io::Read> Read for T {
 	fn read(&mut self, buf: &mut [u8]) -> Result<()> {
 		self.read_exact(buf).map_err(Error::Io)
 	}
+
+	fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> Result<()> {
+		// Drive `std::io`'s vectored read, tracking our own cursor so that every
+		// buffer ends up completely filled even if a call is short.
+		let mut idx = 0;
+		let mut offset = 0;
+		while idx < bufs.len() {
+			// A buffer that is empty or already filled needs no further call.
+			if offset == bufs[idx].len() {
+				idx += 1;
+				offset = 0;
+				continue;
+			}
+			let n = {
+				let mut io_bufs: std::vec::Vec<std::io::IoSliceMut<'_>> = bufs[idx..]
+					.iter_mut()
+					.enumerate()
+					.map(|(i, b)| {
+						std::io::IoSliceMut::new(if i == 0 { &mut b[offset..] } else { &mut b[..] })
+					})
+					.collect();
+				io::Read::read_vectored(self, &mut io_bufs).map_err(Error::Io)?
+			};
+			if n == 0 {
+				return Err(Error::UnexpectedEof);
+			}
+			let mut advance = n;
+			while advance > 0 {
+				let remaining = bufs[idx].len() - offset;
+				if advance >= remaining {
+					advance -= remaining;
+					idx += 1;
+					offset = 0;
+				} else {
+					offset += advance;
+					advance = 0;
+				}
+			}
+		}
+		Ok(())
+	}
 }
 
 #[cfg(feature = "std")]
@@ -135,6 +262,43 @@ impl<T: io::Write> Write for T {
 	fn write(&mut self, buf: &[u8]) -> Result<()> {
 		self.write_all(buf).map_err(Error::Io)
 	}
+
+	fn write_vectored(&mut self, bufs: &[IoSlice]) -> Result<()> {
+		// Drive `std::io`'s vectored write, tracking our own cursor so that every
+		// buffer is written in full even if a call is short.
+		let mut idx = 0;
+		let mut offset = 0;
+		while idx < bufs.len() {
+			// A buffer that is empty or already written needs no further call.
+			if offset == bufs[idx].len() {
+				idx += 1;
+				offset = 0;
+				continue;
+			}
+			let io_bufs: std::vec::Vec<std::io::IoSlice<'_>> = bufs[idx..]
+				.iter()
+				.enumerate()
+				.map(|(i, b)| std::io::IoSlice::new(if i == 0 { &b[offset..] } else { &b[..] }))
+				.collect();
+			let n = io::Write::write_vectored(self, &io_bufs).map_err(Error::Io)?;
+			if n == 0 {
+				return Err(Error::Io(std::io::Error::from(std::io::ErrorKind::WriteZero)));
+			}
+			let mut advance = n;
+			while advance > 0 {
+				let remaining = bufs[idx].len() - offset;
+				if advance >= remaining {
+					advance -= remaining;
+					idx += 1;
+					offset = 0;
+				} else {
+					offset += advance;
+					advance = 0;
+				}
+			}
+		}
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -155,6 +319,30 @@ mod tests {
 		assert_eq!(cursor.position(), 2);
 	}
 
+	#[test]
+	fn cursor_read_vectored() {
+		let mut cursor = Cursor::new(vec![1u8, 2, 3, 4, 5]);
+
+		let mut a = [0u8; 2];
+		let mut b = [0u8; 3];
+		{
+			let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+			cursor.read_vectored(&mut bufs).unwrap();
+		}
+		assert_eq!(a, [1, 2]);
+		assert_eq!(b, [3, 4, 5]);
+		assert_eq!(cursor.position(), 5);
+	}
+
+	#[test]
+	fn cursor_read_vectored_eof() {
+		let mut cursor = Cursor::new(vec![1u8, 2]);
+		let mut a = [0u8; 2];
+		let mut b = [0u8; 2];
+		let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+		assert!(cursor.read_vectored(&mut bufs).is_err());
+	}
+
 	#[test]
 	fn overflow_in_cursor() {
 		let mut cursor = Cursor::new(vec![0u8]);